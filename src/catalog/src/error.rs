@@ -111,6 +111,31 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Cannot deregister the default catalog: {}", catalog_name))]
+    DeregisterDefaultCatalog {
+        catalog_name: String,
+        #[snafu(implicit)]
+        location: Location,
+    },
+
+    #[snafu(display("Cannot deregister the default schema: {}.{}", catalog, schema))]
+    DeregisterDefaultSchema {
+        catalog: String,
+        schema: String,
+        #[snafu(implicit)]
+        location: Location,
+    },
+
+    #[snafu(display(
+        "Cannot deregister the information schema of catalog: {}",
+        catalog_name
+    ))]
+    DeregisterInformationSchema {
+        catalog_name: String,
+        #[snafu(implicit)]
+        location: Location,
+    },
+
     #[snafu(display("Cannot find schema {} in catalog {}", schema, catalog))]
     SchemaNotFound {
         catalog: String,
@@ -133,6 +158,20 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display(
+        "Table id mismatch for table `{}`, expected: {}, actual: {}",
+        table,
+        expected,
+        actual
+    ))]
+    TableIdMismatch {
+        table: String,
+        expected: u32,
+        actual: u32,
+        #[snafu(implicit)]
+        location: Location,
+    },
+
     #[snafu(display("View info not found: {}", name))]
     ViewInfoNotFound {
         name: String,
@@ -282,7 +321,11 @@ impl ErrorExt for Error {
             | Error::CastManager { .. }
             | Error::Json { .. } => StatusCode::Unexpected,
 
-            Error::ViewPlanColumnsChanged { .. } => StatusCode::InvalidArguments,
+            Error::ViewPlanColumnsChanged { .. }
+            | Error::DeregisterDefaultCatalog { .. }
+            | Error::DeregisterDefaultSchema { .. }
+            | Error::DeregisterInformationSchema { .. }
+            | Error::TableIdMismatch { .. } => StatusCode::InvalidArguments,
 
             Error::ViewInfoNotFound { .. } => StatusCode::TableNotFound,
 
@@ -23,7 +23,7 @@ use common_catalog::consts::{
 };
 use common_config::Mode;
 use common_error::ext::BoxedError;
-use common_meta::cache::{LayeredCacheRegistryRef, ViewInfoCacheRef};
+use common_meta::cache::{LayeredCacheRegistryRef, TableInfoCacheRef, ViewInfoCacheRef};
 use common_meta::key::catalog_name::CatalogNameKey;
 use common_meta::key::flow::FlowMetadataManager;
 use common_meta::key::schema_name::SchemaNameKey;
@@ -38,6 +38,7 @@ use moka::sync::Cache;
 use partition::manager::{PartitionRuleManager, PartitionRuleManagerRef};
 use snafu::prelude::*;
 use table::dist_table::DistTable;
+use table::metadata::TableId;
 use table::table::numbers::{NumbersTable, NUMBERS_TABLE_NAME};
 use table::table_name::TableName;
 use table::TableRef;
@@ -106,6 +107,26 @@ impl KvBackendCatalogManager {
         })
     }
 
+    /// Returns a standalone [KvBackendCatalogManager] with the default cache registry for tests.
+    #[cfg(test)]
+    pub(crate) fn new_for_test(backend: KvBackendRef) -> Arc<Self> {
+        use cache::{build_fundamental_cache_registry, with_default_composite_cache_registry};
+        use common_meta::cache::{CacheRegistryBuilder, LayeredCacheRegistryBuilder};
+
+        let layered_cache_builder = LayeredCacheRegistryBuilder::default()
+            .add_cache_registry(CacheRegistryBuilder::default().build());
+        let fundamental_cache_registry = build_fundamental_cache_registry(backend.clone());
+        let layered_cache_registry = Arc::new(
+            with_default_composite_cache_registry(
+                layered_cache_builder.add_cache_registry(fundamental_cache_registry),
+            )
+            .unwrap()
+            .build(),
+        );
+
+        Self::new(Mode::Standalone, None, backend, layered_cache_registry)
+    }
+
     /// Returns the server running mode.
     pub fn running_mode(&self) -> &Mode {
         &self.mode
@@ -129,6 +150,25 @@ impl KvBackendCatalogManager {
     pub fn table_metadata_manager_ref(&self) -> &TableMetadataManagerRef {
         &self.table_metadata_manager
     }
+
+    /// Returns the user table by table id, resolved through the table info cache.
+    ///
+    /// System tables (e.g. `numbers` and the `information_schema` tables) have no
+    /// table info in the backend, so this returns `None` for their ids even though
+    /// [CatalogManager::table] resolves them by name.
+    pub async fn table_by_id(&self, table_id: TableId) -> Result<Option<TableRef>> {
+        let table_info_cache: TableInfoCacheRef =
+            self.cache_registry.get().context(CacheNotFoundSnafu {
+                name: "table_info_cache",
+            })?;
+
+        let table_info = table_info_cache
+            .get_by_ref(&table_id)
+            .await
+            .context(GetTableCacheSnafu)?;
+
+        Ok(table_info.map(DistTable::table))
+    }
 }
 
 #[async_trait::async_trait]
@@ -214,10 +254,9 @@ impl CatalogManager for KvBackendCatalogManager {
         let key = TableNameKey::new(catalog, schema, table);
         self.table_metadata_manager
             .table_name_manager()
-            .get(key)
+            .exists(key)
             .await
             .context(TableMetadataManagerSnafu)
-            .map(|x| x.is_some())
     }
 
     async fn table(
@@ -386,3 +425,88 @@ impl SystemCatalog {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common_meta::key::table_route::TableRouteValue;
+    use common_meta::key::test_utils::new_test_table_info_with_name;
+    use common_meta::kv_backend::memory::MemoryKvBackend;
+
+    use super::*;
+
+    async fn create_tables(backend: KvBackendRef, tables: &[(TableId, &str)]) {
+        let table_metadata_manager = TableMetadataManager::new(backend);
+        for (table_id, table_name) in tables {
+            table_metadata_manager
+                .create_table_metadata(
+                    new_test_table_info_with_name(*table_id, table_name, vec![]).into(),
+                    TableRouteValue::physical(vec![]),
+                    HashMap::new(),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_table_by_id() {
+        let backend: KvBackendRef = Arc::new(MemoryKvBackend::default());
+        let catalog_manager = KvBackendCatalogManager::new_for_test(backend.clone());
+        let tables = [(1024, "foo"), (1025, "bar")];
+        create_tables(backend, &tables).await;
+
+        for (table_id, table_name) in tables {
+            let table = catalog_manager
+                .table_by_id(table_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(table_id, table.table_info().table_id());
+            assert_eq!(table_name, table.table_info().name);
+        }
+        assert!(catalog_manager.table_by_id(1026).await.unwrap().is_none());
+
+        // System tables are only resolvable by name.
+        assert!(catalog_manager
+            .table_by_id(NUMBERS_TABLE_ID)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(catalog_manager
+            .table(
+                DEFAULT_CATALOG_NAME,
+                DEFAULT_SCHEMA_NAME,
+                NUMBERS_TABLE_NAME
+            )
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_table_exists() {
+        let backend: KvBackendRef = Arc::new(MemoryKvBackend::default());
+        let catalog_manager = KvBackendCatalogManager::new_for_test(backend.clone());
+        create_tables(backend, &[(1024, "foo")]).await;
+
+        assert!(catalog_manager
+            .table_exists(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "foo")
+            .await
+            .unwrap());
+        assert!(!catalog_manager
+            .table_exists(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "bar")
+            .await
+            .unwrap());
+        // System tables are resolved without touching the backend.
+        assert!(catalog_manager
+            .table_exists(
+                DEFAULT_CATALOG_NAME,
+                DEFAULT_SCHEMA_NAME,
+                NUMBERS_TABLE_NAME
+            )
+            .await
+            .unwrap());
+    }
+}
@@ -26,13 +26,20 @@ use common_catalog::consts::{
 use common_meta::key::flow::FlowMetadataManager;
 use common_meta::kv_backend::memory::MemoryKvBackend;
 use futures_util::stream::BoxStream;
-use snafu::OptionExt;
-use table::TableRef;
+use snafu::{ensure, OptionExt};
+use table::{Table, TableRef};
 
-use crate::error::{CatalogNotFoundSnafu, Result, SchemaNotFoundSnafu, TableExistsSnafu};
+use crate::error::{
+    CatalogNotFoundSnafu, DeregisterDefaultCatalogSnafu, DeregisterDefaultSchemaSnafu,
+    DeregisterInformationSchemaSnafu, Result, SchemaNotFoundSnafu, TableExistsSnafu,
+    TableIdMismatchSnafu, TableNotExistSnafu,
+};
 use crate::information_schema::InformationSchemaProvider;
 use crate::system_schema::SystemSchemaProvider;
-use crate::{CatalogManager, DeregisterTableRequest, RegisterSchemaRequest, RegisterTableRequest};
+use crate::{
+    CatalogManager, DeregisterSchemaRequest, DeregisterTableRequest, RegisterSchemaRequest,
+    RegisterTableRequest, RenameTableRequest,
+};
 
 type SchemaEntries = HashMap<String, HashMap<String, TableRef>>;
 
@@ -227,7 +234,39 @@ impl MemoryCatalogManager {
         }
     }
 
-    pub fn deregister_table_sync(&self, request: DeregisterTableRequest) -> Result<()> {
+    /// Deregisters a catalog together with all the schemas and tables in it.
+    /// It returns an error if the catalog is the default catalog,
+    /// and returns false if the catalog does not exist.
+    pub fn deregister_catalog_sync(&self, name: &str) -> Result<bool> {
+        ensure!(
+            name != DEFAULT_CATALOG_NAME,
+            DeregisterDefaultCatalogSnafu { catalog_name: name }
+        );
+
+        let mut catalogs = self.catalogs.write().unwrap();
+        let Some(schemas) = catalogs.remove(name) else {
+            return Ok(false);
+        };
+        crate::metrics::METRIC_CATALOG_MANAGER_CATALOG_COUNT.dec();
+        // The information schema is created along with the catalog and is not counted.
+        for (schema, tables) in schemas
+            .iter()
+            .filter(|(schema, _)| *schema != INFORMATION_SCHEMA_NAME)
+        {
+            crate::metrics::METRIC_CATALOG_MANAGER_SCHEMA_COUNT.dec();
+            crate::metrics::METRIC_CATALOG_MANAGER_TABLE_COUNT
+                .with_label_values(&[build_db_string(name, schema).as_str()])
+                .sub(tables.len() as i64);
+        }
+        Ok(true)
+    }
+
+    /// Deregisters a table and returns the removed table if it exists.
+    /// It returns an error if the catalog or schema does not exist.
+    pub fn deregister_table_sync(
+        &self,
+        request: DeregisterTableRequest,
+    ) -> Result<Option<TableRef>> {
         let mut catalogs = self.catalogs.write().unwrap();
         let schema = catalogs
             .get_mut(&request.catalog)
@@ -245,7 +284,7 @@ impl MemoryCatalogManager {
                 .with_label_values(&[build_db_string(&request.catalog, &request.schema).as_str()])
                 .dec();
         }
-        Ok(())
+        Ok(result)
     }
 
     /// Registers a schema if it does not exist.
@@ -269,6 +308,98 @@ impl MemoryCatalogManager {
         }
     }
 
+    /// Deregisters a schema together with all the tables in it and returns
+    /// the number of tables removed. It returns an error if the schema is the information schema,
+    /// the default schema of the default catalog, or the catalog does not exist,
+    /// and returns 0 if the schema does not exist.
+    pub fn deregister_schema_sync(&self, request: DeregisterSchemaRequest) -> Result<usize> {
+        ensure!(
+            request.schema != INFORMATION_SCHEMA_NAME,
+            DeregisterInformationSchemaSnafu {
+                catalog_name: &request.catalog,
+            }
+        );
+        ensure!(
+            request.catalog != DEFAULT_CATALOG_NAME || request.schema != DEFAULT_SCHEMA_NAME,
+            DeregisterDefaultSchemaSnafu {
+                catalog: &request.catalog,
+                schema: &request.schema,
+            }
+        );
+
+        let mut catalogs = self.catalogs.write().unwrap();
+        let catalog = catalogs
+            .get_mut(&request.catalog)
+            .with_context(|| CatalogNotFoundSnafu {
+                catalog_name: &request.catalog,
+            })?;
+
+        let Some(tables) = catalog.remove(&request.schema) else {
+            return Ok(0);
+        };
+        crate::metrics::METRIC_CATALOG_MANAGER_SCHEMA_COUNT.dec();
+        crate::metrics::METRIC_CATALOG_MANAGER_TABLE_COUNT
+            .with_label_values(&[build_db_string(&request.catalog, &request.schema).as_str()])
+            .sub(tables.len() as i64);
+        Ok(tables.len())
+    }
+
+    /// Renames a table and keeps the same table id and data source.
+    /// Renaming a table to its current name is a no-op.
+    /// It returns an error if the catalog, schema or table does not exist,
+    /// the table id doesn't match the request, or the new table name is already taken.
+    pub fn rename_table_sync(&self, request: RenameTableRequest) -> Result<()> {
+        let mut catalogs = self.catalogs.write().unwrap();
+        let schema = catalogs
+            .get_mut(&request.catalog)
+            .with_context(|| CatalogNotFoundSnafu {
+                catalog_name: &request.catalog,
+            })?
+            .get_mut(&request.schema)
+            .with_context(|| SchemaNotFoundSnafu {
+                catalog: &request.catalog,
+                schema: &request.schema,
+            })?;
+
+        let table = schema
+            .get(&request.table_name)
+            .with_context(|| TableNotExistSnafu {
+                table: &request.table_name,
+            })?;
+        let table_id = table.table_info().table_id();
+        ensure!(
+            table_id == request.table_id,
+            TableIdMismatchSnafu {
+                table: &request.table_name,
+                expected: request.table_id,
+                actual: table_id,
+            }
+        );
+        if request.table_name == request.new_table_name {
+            return Ok(());
+        }
+
+        ensure!(
+            !schema.contains_key(&request.new_table_name),
+            TableExistsSnafu {
+                table: &request.new_table_name,
+            }
+        );
+        // Safety: the table exists as checked above.
+        let table = schema.remove(&request.table_name).unwrap();
+
+        // The table info carries the table name, so the handle is rebuilt with the new name.
+        let mut table_info = table.table_info().as_ref().clone();
+        table_info.name.clone_from(&request.new_table_name);
+        let table = Table::new(
+            Arc::new(table_info),
+            table.filter_pushdown(),
+            table.data_source(),
+        );
+        schema.insert(request.new_table_name, Arc::new(table));
+        Ok(())
+    }
+
     /// Registers a schema and returns an error if the catalog or schema does not exist.
     pub fn register_table_sync(&self, request: RegisterTableRequest) -> Result<bool> {
         let mut catalogs = self.catalogs.write().unwrap();
@@ -347,11 +478,14 @@ pub fn new_memory_catalog_manager() -> Result<Arc<MemoryCatalogManager>> {
 
 #[cfg(test)]
 mod tests {
+    use std::assert_matches::assert_matches;
+
     use common_catalog::consts::*;
     use futures_util::TryStreamExt;
     use table::table::numbers::{NumbersTable, NUMBERS_TABLE_NAME};
 
     use super::*;
+    use crate::error::Error;
 
     #[tokio::test]
     async fn test_new_memory_catalog_list() {
@@ -397,6 +531,19 @@ mod tests {
         assert!(!list.register_catalog_sync("test_catalog").unwrap());
     }
 
+    #[test]
+    pub fn test_register_schema_sync_without_catalog() {
+        let list = MemoryCatalogManager::with_default_setup();
+        let err = list
+            .register_schema_sync(RegisterSchemaRequest {
+                catalog: "not_exists".to_string(),
+                schema: "foo_schema".to_string(),
+            })
+            .unwrap_err();
+        assert_matches!(err, Error::CatalogNotFound { .. });
+        assert!(!list.catalog_exist_sync("not_exists").unwrap());
+    }
+
     #[tokio::test]
     pub async fn test_catalog_deregister_table() {
         let catalog = MemoryCatalogManager::with_default_setup();
@@ -421,11 +568,262 @@ mod tests {
             schema: DEFAULT_SCHEMA_NAME.to_string(),
             table_name: table_name.to_string(),
         };
-        catalog.deregister_table_sync(deregister_table_req).unwrap();
+        let removed = catalog
+            .deregister_table_sync(deregister_table_req.clone())
+            .unwrap()
+            .unwrap();
+        assert_eq!(2333, removed.table_info().table_id());
         assert!(catalog
             .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, table_name)
             .await
             .unwrap()
             .is_none());
+        assert!(!catalog
+            .table_exists(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, table_name)
+            .await
+            .unwrap());
+
+        // Deregistering an absent table is a no-op.
+        assert!(catalog
+            .deregister_table_sync(deregister_table_req)
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_deregister_schema() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+        let schema_name = "foo_schema";
+        assert!(catalog
+            .register_schema_sync(RegisterSchemaRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: schema_name.to_string(),
+            })
+            .unwrap());
+
+        for (table_id, table_name) in [(2333, "foo"), (2334, "bar"), (2335, "baz")] {
+            catalog
+                .register_table_sync(RegisterTableRequest {
+                    catalog: DEFAULT_CATALOG_NAME.to_string(),
+                    schema: schema_name.to_string(),
+                    table_name: table_name.to_string(),
+                    table_id,
+                    table: NumbersTable::table(table_id),
+                })
+                .unwrap();
+        }
+
+        let request = DeregisterSchemaRequest {
+            catalog: DEFAULT_CATALOG_NAME.to_string(),
+            schema: schema_name.to_string(),
+        };
+        assert_eq!(3, catalog.deregister_schema_sync(request.clone()).unwrap());
+        assert!(!catalog
+            .schema_exists(DEFAULT_CATALOG_NAME, schema_name)
+            .await
+            .unwrap());
+        for table_name in ["foo", "bar", "baz"] {
+            assert!(catalog
+                .table(DEFAULT_CATALOG_NAME, schema_name, table_name)
+                .await
+                .unwrap()
+                .is_none());
+        }
+
+        // Deregistering an absent schema is a no-op.
+        assert_eq!(0, catalog.deregister_schema_sync(request).unwrap());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_deregister_information_schema() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+
+        let err = catalog
+            .deregister_schema_sync(DeregisterSchemaRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: INFORMATION_SCHEMA_NAME.to_string(),
+            })
+            .unwrap_err();
+        assert_matches!(err, Error::DeregisterInformationSchema { .. });
+        assert!(catalog
+            .schema_exists(DEFAULT_CATALOG_NAME, INFORMATION_SCHEMA_NAME)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_deregister_default_schema() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+
+        let err = catalog
+            .deregister_schema_sync(DeregisterSchemaRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: DEFAULT_SCHEMA_NAME.to_string(),
+            })
+            .unwrap_err();
+        assert_matches!(err, Error::DeregisterDefaultSchema { .. });
+        assert!(catalog
+            .schema_exists(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME)
+            .await
+            .unwrap());
+
+        // A schema named after the default schema in another catalog can be deregistered.
+        let catalog_name = "foo_catalog";
+        assert!(catalog.register_catalog_sync(catalog_name).unwrap());
+        assert!(catalog
+            .register_schema_sync(RegisterSchemaRequest {
+                catalog: catalog_name.to_string(),
+                schema: DEFAULT_SCHEMA_NAME.to_string(),
+            })
+            .unwrap());
+        assert_eq!(
+            0,
+            catalog
+                .deregister_schema_sync(DeregisterSchemaRequest {
+                    catalog: catalog_name.to_string(),
+                    schema: DEFAULT_SCHEMA_NAME.to_string(),
+                })
+                .unwrap()
+        );
+        assert!(!catalog
+            .schema_exists(catalog_name, DEFAULT_SCHEMA_NAME)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_deregister_catalog() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+        let catalog_name = "foo_catalog";
+        let schema_name = "foo_schema";
+        assert!(catalog.register_catalog_sync(catalog_name).unwrap());
+        assert!(catalog
+            .register_schema_sync(RegisterSchemaRequest {
+                catalog: catalog_name.to_string(),
+                schema: schema_name.to_string(),
+            })
+            .unwrap());
+        catalog
+            .register_table_sync(RegisterTableRequest {
+                catalog: catalog_name.to_string(),
+                schema: schema_name.to_string(),
+                table_name: "foo_table".to_string(),
+                table_id: 2333,
+                table: NumbersTable::table(2333),
+            })
+            .unwrap();
+
+        assert!(catalog.deregister_catalog_sync(catalog_name).unwrap());
+        assert!(!catalog.catalog_exists(catalog_name).await.unwrap());
+        assert!(catalog
+            .table(catalog_name, schema_name, "foo_table")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!catalog.deregister_catalog_sync(catalog_name).unwrap());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_deregister_default_catalog() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+
+        let err = catalog
+            .deregister_catalog_sync(DEFAULT_CATALOG_NAME)
+            .unwrap_err();
+        assert_matches!(err, Error::DeregisterDefaultCatalog { .. });
+        assert!(catalog.catalog_exists(DEFAULT_CATALOG_NAME).await.unwrap());
+    }
+
+    #[tokio::test]
+    pub async fn test_catalog_rename_table() {
+        let catalog = MemoryCatalogManager::with_default_setup();
+        for (table_id, table_name) in [(2333, "foo"), (2334, "bar")] {
+            catalog
+                .register_table_sync(RegisterTableRequest {
+                    catalog: DEFAULT_CATALOG_NAME.to_string(),
+                    schema: DEFAULT_SCHEMA_NAME.to_string(),
+                    table_name: table_name.to_string(),
+                    table_id,
+                    table: NumbersTable::table(table_id),
+                })
+                .unwrap();
+        }
+
+        let request = RenameTableRequest {
+            catalog: DEFAULT_CATALOG_NAME.to_string(),
+            schema: DEFAULT_SCHEMA_NAME.to_string(),
+            table_name: "foo".to_string(),
+            new_table_name: "baz".to_string(),
+            table_id: 2333,
+        };
+        catalog.rename_table_sync(request.clone()).unwrap();
+        assert!(catalog
+            .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "foo")
+            .await
+            .unwrap()
+            .is_none());
+        let table = catalog
+            .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "baz")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(2333, table.table_info().table_id());
+        assert_eq!("baz", table.table_info().name);
+
+        // The old name no longer exists.
+        let err = catalog.rename_table_sync(request).unwrap_err();
+        assert_matches!(err, Error::TableNotExist { .. });
+
+        // The new name is already taken.
+        let err = catalog
+            .rename_table_sync(RenameTableRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: DEFAULT_SCHEMA_NAME.to_string(),
+                table_name: "baz".to_string(),
+                new_table_name: "bar".to_string(),
+                table_id: 2333,
+            })
+            .unwrap_err();
+        assert_matches!(err, Error::TableExists { .. });
+
+        // The table id doesn't match the table registered under the name.
+        let err = catalog
+            .rename_table_sync(RenameTableRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: DEFAULT_SCHEMA_NAME.to_string(),
+                table_name: "baz".to_string(),
+                new_table_name: "qux".to_string(),
+                table_id: 2334,
+            })
+            .unwrap_err();
+        assert_matches!(err, Error::TableIdMismatch { .. });
+        assert!(catalog
+            .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "baz")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(catalog
+            .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "qux")
+            .await
+            .unwrap()
+            .is_none());
+
+        // Renaming a table to its current name is a no-op.
+        catalog
+            .rename_table_sync(RenameTableRequest {
+                catalog: DEFAULT_CATALOG_NAME.to_string(),
+                schema: DEFAULT_SCHEMA_NAME.to_string(),
+                table_name: "baz".to_string(),
+                new_table_name: "baz".to_string(),
+                table_id: 2333,
+            })
+            .unwrap();
+        let table = catalog
+            .table(DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, "baz")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(2333, table.table_info().table_id());
+        assert_eq!("baz", table.table_info().name);
     }
 }
@@ -257,9 +257,6 @@ mod tests {
     use std::collections::HashSet;
 
     use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
-    use cache::{build_fundamental_cache_registry, with_default_composite_cache_registry};
-    use common_config::Mode;
-    use common_meta::cache::{CacheRegistryBuilder, LayeredCacheRegistryBuilder};
     use common_meta::key::TableMetadataManager;
     use common_meta::kv_backend::memory::MemoryKvBackend;
     use common_query::error::Result as QueryResult;
@@ -310,23 +307,7 @@ mod tests {
     async fn test_resolve_view() {
         let query_ctx = &QueryContext::with("greptime", "public");
         let backend = Arc::new(MemoryKvBackend::default());
-        let layered_cache_builder = LayeredCacheRegistryBuilder::default()
-            .add_cache_registry(CacheRegistryBuilder::default().build());
-        let fundamental_cache_registry = build_fundamental_cache_registry(backend.clone());
-        let layered_cache_registry = Arc::new(
-            with_default_composite_cache_registry(
-                layered_cache_builder.add_cache_registry(fundamental_cache_registry),
-            )
-            .unwrap()
-            .build(),
-        );
-
-        let catalog_manager = KvBackendCatalogManager::new(
-            Mode::Standalone,
-            None,
-            backend.clone(),
-            layered_cache_registry,
-        );
+        let catalog_manager = KvBackendCatalogManager::new_for_test(backend.clone());
         let table_metadata_manager = TableMetadataManager::new(backend);
         let mut view_info = common_meta::key::test_utils::new_test_table_info(1024, vec![]);
         view_info.table_type = TableType::View;
@@ -20,13 +20,15 @@ use cache::{build_fundamental_cache_registry, with_default_composite_cache_regis
 use catalog::kvbackend::KvBackendCatalogManager;
 use clap::Parser;
 use common_base::Plugins;
-use common_catalog::consts::{MIN_USER_FLOW_ID, MIN_USER_TABLE_ID};
+use common_catalog::consts::MIN_USER_FLOW_ID;
 use common_config::{metadata_store_dir, Configurable, KvBackendConfig};
 use common_error::ext::BoxedError;
 use common_meta::cache::LayeredCacheRegistryBuilder;
 use common_meta::cache_invalidator::CacheInvalidatorRef;
 use common_meta::ddl::flow_meta::{FlowMetadataAllocator, FlowMetadataAllocatorRef};
-use common_meta::ddl::table_meta::{TableMetadataAllocator, TableMetadataAllocatorRef};
+use common_meta::ddl::table_meta::{
+    table_id_sequence_builder, TableMetadataAllocator, TableMetadataAllocatorRef,
+};
 use common_meta::ddl::{DdlContext, NoopRegionFailureDetectorControl, ProcedureExecutorRef};
 use common_meta::ddl_manager::DdlManager;
 use common_meta::key::flow::{FlowMetadataManager, FlowMetadataManagerRef};
@@ -497,12 +499,8 @@ impl StartCommand {
             flow_server: flownode.flow_worker_manager(),
         });
 
-        let table_id_sequence = Arc::new(
-            SequenceBuilder::new(TABLE_ID_SEQ, kv_backend.clone())
-                .initial(MIN_USER_TABLE_ID as u64)
-                .step(10)
-                .build(),
-        );
+        let table_id_sequence =
+            Arc::new(table_id_sequence_builder(TABLE_ID_SEQ, kv_backend.clone()).build());
         let flow_id_sequence = Arc::new(
             SequenceBuilder::new(FLOW_ID_SEQ, kv_backend.clone())
                 .initial(MIN_USER_FLOW_ID as u64)
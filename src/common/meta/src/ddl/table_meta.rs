@@ -16,21 +16,34 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_catalog::consts::MIN_USER_TABLE_ID;
 use common_telemetry::{debug, info};
 use snafu::ensure;
 use store_api::storage::{RegionId, RegionNumber, TableId};
 
 use crate::ddl::{TableMetadata, TableMetadataAllocatorContext};
-use crate::error::{self, Result, UnsupportedSnafu};
+use crate::error::{self, Result, TableIdExhaustedSnafu, UnsupportedSnafu};
 use crate::key::table_route::PhysicalTableRouteValue;
+use crate::kv_backend::KvBackendRef;
 use crate::peer::Peer;
 use crate::rpc::ddl::CreateTableTask;
 use crate::rpc::router::{Region, RegionRoute};
-use crate::sequence::SequenceRef;
+use crate::sequence::{SequenceBuilder, SequenceRef};
 use crate::wal_options_allocator::{allocate_region_wal_options, WalOptionsAllocatorRef};
 
 pub type TableMetadataAllocatorRef = Arc<TableMetadataAllocator>;
 
+/// Returns a [SequenceBuilder] for the table id sequence named `name`.
+///
+/// The sequence starts from [MIN_USER_TABLE_ID] and is bounded by [TableId::MAX],
+/// so the allocated ids never wrap when converted to [TableId].
+pub fn table_id_sequence_builder(name: &str, kv_backend: KvBackendRef) -> SequenceBuilder {
+    SequenceBuilder::new(name, kv_backend)
+        .initial(MIN_USER_TABLE_ID as u64)
+        .step(10)
+        .max(TableId::MAX as u64)
+}
+
 #[derive(Clone)]
 pub struct TableMetadataAllocator {
     table_id_sequence: SequenceRef,
@@ -90,11 +103,31 @@ impl TableMetadataAllocator {
 
             table_id
         } else {
-            self.table_id_sequence.next().await? as TableId
+            match self.table_id_sequence.next().await {
+                Ok(table_id) => table_id as TableId,
+                Err(e) => {
+                    ensure!(
+                        self.remaining_table_ids().await? > 0,
+                        TableIdExhaustedSnafu {
+                            max: self.table_id_sequence.min_max().await.end as TableId,
+                        }
+                    );
+                    return Err(e);
+                }
+            }
         };
         Ok(table_id)
     }
 
+    /// Returns the number of table ids that haven't been leased by any allocator yet.
+    ///
+    /// Ids already leased but not yet used by an allocator are not counted.
+    pub async fn remaining_table_ids(&self) -> Result<u64> {
+        let max = self.table_id_sequence.min_max().await.end;
+        let next = self.table_id_sequence.peek().await?;
+        Ok(max.saturating_sub(next))
+    }
+
     fn create_wal_options(
         &self,
         table_route: &PhysicalTableRouteValue,
@@ -204,3 +237,41 @@ impl PeerAllocator for NoopPeerAllocator {
         Ok(vec![Peer::default(); regions])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::kv_backend::memory::MemoryKvBackend;
+    use crate::wal_options_allocator::WalOptionsAllocator;
+
+    #[tokio::test]
+    async fn test_allocate_table_id_exhausted() {
+        let kv_backend = Arc::new(MemoryKvBackend::new());
+        let sequence = table_id_sequence_builder("test", kv_backend)
+            .initial(TableId::MAX as u64 - 2)
+            .build();
+        let allocator = TableMetadataAllocator::new(
+            Arc::new(sequence),
+            Arc::new(WalOptionsAllocator::default()),
+        );
+
+        assert_eq!(2, allocator.remaining_table_ids().await.unwrap());
+        assert_eq!(
+            TableId::MAX - 2,
+            allocator.allocate_table_id(&None).await.unwrap()
+        );
+        // Both remaining ids are leased by the first allocation.
+        assert_eq!(0, allocator.remaining_table_ids().await.unwrap());
+        assert_eq!(
+            TableId::MAX - 1,
+            allocator.allocate_table_id(&None).await.unwrap()
+        );
+
+        // The next id would wrap around when converted to `TableId`.
+        let err = allocator.allocate_table_id(&None).await.unwrap_err();
+        assert_matches!(err, Error::TableIdExhausted { .. });
+    }
+}
@@ -95,6 +95,13 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Table id exhausted, max table id: {}", max))]
+    TableIdExhausted {
+        max: u32,
+        #[snafu(implicit)]
+        location: Location,
+    },
+
     #[snafu(display("Sequence out of range: {}, start={}, step={}", name, start, step))]
     SequenceOutOfRange {
         name: String,
@@ -707,6 +714,7 @@ impl ErrorExt for Error {
             | Unexpected { .. }
             | TableInfoNotFound { .. }
             | NextSequence { .. }
+            | TableIdExhausted { .. }
             | SequenceOutOfRange { .. }
             | UnexpectedSequenceValue { .. }
             | InvalidHeartbeatResponse { .. }
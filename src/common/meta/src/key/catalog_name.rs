@@ -140,7 +140,9 @@ impl CatalogManager {
 
     pub fn catalog_names(&self) -> BoxStream<'static, Result<String>> {
         let start_key = CatalogNameKey::range_start_key();
-        let req = RangeRequest::new().with_prefix(start_key.as_bytes());
+        let req = RangeRequest::new()
+            .with_prefix(start_key.as_bytes())
+            .with_keys_only();
 
         let stream = PaginationStream::new(
             self.kv_backend.clone(),
@@ -155,8 +157,11 @@ impl CatalogManager {
 
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+
     use super::*;
     use crate::kv_backend::memory::MemoryKvBackend;
+    use crate::kv_backend::test::RangeRecordingKvBackend;
 
     #[test]
     fn test_serialization() {
@@ -183,4 +188,29 @@ mod tests {
 
         assert!(!manager.exists(wrong_catalog_key).await.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_catalog_names() {
+        let kv_backend = Arc::new(RangeRecordingKvBackend::default());
+        let manager = CatalogManager::new(kv_backend.clone());
+
+        for catalog in ["catalog-b", "catalog-a"] {
+            manager
+                .create(CatalogNameKey::new(catalog), false)
+                .await
+                .unwrap();
+        }
+
+        let catalogs = manager
+            .catalog_names()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(vec!["catalog-a", "catalog-b"], catalogs);
+        // Only the keys are fetched since the catalog name is decoded from the key.
+        let range_requests = kv_backend.range_requests();
+        assert!(!range_requests.is_empty());
+        assert!(range_requests.iter().all(|req| req.keys_only));
+    }
 }
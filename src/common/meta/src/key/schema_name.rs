@@ -210,7 +210,9 @@ impl SchemaManager {
     /// Returns a schema stream, it lists all schemas belong to the target `catalog`.
     pub fn schema_names(&self, catalog: &str) -> BoxStream<'static, Result<String>> {
         let start_key = SchemaNameKey::range_start_key(catalog);
-        let req = RangeRequest::new().with_prefix(start_key.as_bytes());
+        let req = RangeRequest::new()
+            .with_prefix(start_key.as_bytes())
+            .with_keys_only();
 
         let stream = PaginationStream::new(
             self.kv_backend.clone(),
@@ -240,9 +242,11 @@ impl<'a> From<&'a SchemaName> for SchemaNameKey<'a> {
 
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
 
     use super::*;
     use crate::kv_backend::memory::MemoryKvBackend;
+    use crate::kv_backend::test::RangeRecordingKvBackend;
 
     #[test]
     fn test_display_schema_value() {
@@ -292,4 +296,33 @@ mod tests {
 
         assert!(!manager.exists(wrong_schema_key).await.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_schema_names() {
+        let kv_backend = Arc::new(RangeRecordingKvBackend::default());
+        let manager = SchemaManager::new(kv_backend.clone());
+
+        for (catalog, schema) in [
+            ("my-catalog", "schema-b"),
+            ("my-catalog", "schema-a"),
+            ("other-catalog", "schema-c"),
+        ] {
+            manager
+                .create(SchemaNameKey::new(catalog, schema), None, false)
+                .await
+                .unwrap();
+        }
+
+        let schemas = manager
+            .schema_names("my-catalog")
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(vec!["schema-a", "schema-b"], schemas);
+        // Only the keys are fetched since the schema name is decoded from the key.
+        let range_requests = kv_backend.range_requests();
+        assert!(!range_requests.is_empty());
+        assert!(range_requests.iter().all(|req| req.keys_only));
+    }
 }
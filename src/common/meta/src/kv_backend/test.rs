@@ -12,15 +12,85 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
 use std::sync::atomic::{AtomicU8, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use super::{KvBackend, *};
-use crate::error::Error;
-use crate::rpc::store::{BatchGetRequest, PutRequest};
+use crate::error::{Error, Result};
+use crate::kv_backend::memory::MemoryKvBackend;
+use crate::kv_backend::txn::{Txn, TxnResponse};
+use crate::rpc::store::{
+    BatchDeleteRequest, BatchDeleteResponse, BatchGetRequest, BatchGetResponse, BatchPutRequest,
+    BatchPutResponse, DeleteRangeRequest, DeleteRangeResponse, PutRequest, PutResponse,
+    RangeRequest, RangeResponse,
+};
 use crate::rpc::KeyValue;
 use crate::util;
 
+/// A [KvBackend] backed by a [MemoryKvBackend] that records every [RangeRequest] it receives.
+#[derive(Default)]
+pub struct RangeRecordingKvBackend {
+    inner: MemoryKvBackend<Error>,
+    range_requests: Mutex<Vec<RangeRequest>>,
+}
+
+impl RangeRecordingKvBackend {
+    /// Returns the recorded range requests.
+    pub fn range_requests(&self) -> Vec<RangeRequest> {
+        self.range_requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TxnService for RangeRecordingKvBackend {
+    type Error = Error;
+
+    async fn txn(&self, txn: Txn) -> Result<TxnResponse> {
+        self.inner.txn(txn).await
+    }
+
+    fn max_txn_ops(&self) -> usize {
+        self.inner.max_txn_ops()
+    }
+}
+
+#[async_trait::async_trait]
+impl KvBackend for RangeRecordingKvBackend {
+    fn name(&self) -> &str {
+        "RangeRecording"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn range(&self, req: RangeRequest) -> Result<RangeResponse> {
+        self.range_requests.lock().unwrap().push(req.clone());
+        self.inner.range(req).await
+    }
+
+    async fn put(&self, req: PutRequest) -> Result<PutResponse> {
+        self.inner.put(req).await
+    }
+
+    async fn batch_put(&self, req: BatchPutRequest) -> Result<BatchPutResponse> {
+        self.inner.batch_put(req).await
+    }
+
+    async fn batch_get(&self, req: BatchGetRequest) -> Result<BatchGetResponse> {
+        self.inner.batch_get(req).await
+    }
+
+    async fn delete_range(&self, req: DeleteRangeRequest) -> Result<DeleteRangeResponse> {
+        self.inner.delete_range(req).await
+    }
+
+    async fn batch_delete(&self, req: BatchDeleteRequest) -> Result<BatchDeleteResponse> {
+        self.inner.batch_delete(req).await
+    }
+}
+
 pub fn mock_kvs(prefix: Vec<u8>) -> Vec<KeyValue> {
     vec![
         KeyValue {
@@ -91,6 +91,13 @@ impl Sequence {
         let inner = self.inner.lock().await;
         inner.initial..inner.max
     }
+
+    /// Returns the start of the next range to be fetched from the generator,
+    /// i.e., the first value that hasn't been leased by any sequence yet.
+    pub async fn peek(&self) -> Result<u64> {
+        let inner = self.inner.lock().await;
+        inner.peek().await
+    }
 }
 
 struct Inner {
@@ -139,6 +146,21 @@ impl Inner {
         .fail()
     }
 
+    pub async fn peek(&self) -> Result<u64> {
+        let Some(kv) = self.generator.get(self.name.as_bytes()).await? else {
+            return Ok(self.initial);
+        };
+        let v: [u8; 8] = kv.value.try_into().map_err(|v| {
+            error::UnexpectedSequenceValueSnafu {
+                err_msg: format!("Not a valid u64 for '{}': {v:?}", self.name),
+            }
+            .build()
+        })?;
+
+        // If the existed value is smaller than the initial, we should start from the initial.
+        Ok(u64::from_le_bytes(v).max(self.initial))
+    }
+
     pub async fn next_range(&self) -> Result<Range<u64>> {
         let key = self.name.as_bytes();
         let mut start = self.next;
@@ -307,6 +329,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_sequence_peek() {
+        let kv_backend = Arc::new(MemoryKvBackend::default());
+        let initial = 1024;
+        let seq = SequenceBuilder::new("test_seq", kv_backend)
+            .initial(initial)
+            .step(10)
+            .build();
+
+        // Nothing has been leased yet.
+        assert_eq!(initial, seq.peek().await.unwrap());
+
+        // The first call leases [initial, initial + 10).
+        assert_eq!(initial, seq.next().await.unwrap());
+        assert_eq!(initial + 10, seq.peek().await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_sequence_out_of_rage() {
         let seq = SequenceBuilder::new("test_seq", Arc::new(MemoryKvBackend::default()))
@@ -18,10 +18,12 @@ use std::time::Duration;
 
 use client::client_manager::NodeClients;
 use common_base::Plugins;
-use common_catalog::consts::{MIN_USER_FLOW_ID, MIN_USER_TABLE_ID};
+use common_catalog::consts::MIN_USER_FLOW_ID;
 use common_grpc::channel_manager::ChannelConfig;
 use common_meta::ddl::flow_meta::FlowMetadataAllocator;
-use common_meta::ddl::table_meta::{TableMetadataAllocator, TableMetadataAllocatorRef};
+use common_meta::ddl::table_meta::{
+    table_id_sequence_builder, TableMetadataAllocator, TableMetadataAllocatorRef,
+};
 use common_meta::ddl::{
     DdlContext, NoopRegionFailureDetectorControl, RegionFailureDetectorControllerRef,
 };
@@ -233,12 +235,8 @@ impl MetasrvBuilder {
         ));
         let is_remote_wal = wal_options_allocator.is_remote_wal();
         let table_metadata_allocator = table_metadata_allocator.unwrap_or_else(|| {
-            let sequence = Arc::new(
-                SequenceBuilder::new(TABLE_ID_SEQ, kv_backend.clone())
-                    .initial(MIN_USER_TABLE_ID as u64)
-                    .step(10)
-                    .build(),
-            );
+            let sequence =
+                Arc::new(table_id_sequence_builder(TABLE_ID_SEQ, kv_backend.clone()).build());
             let peer_allocator = Arc::new(MetasrvPeerAllocator::new(
                 selector_ctx.clone(),
                 selector.clone(),
@@ -1504,6 +1504,8 @@ fn merge_options(mut table_opts: TableOptions, schema_opts: SchemaNameValue) ->
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use session::context::{QueryContext, QueryContextBuilder};
     use sql::dialect::GreptimeDbDialect;
     use sql::parser::{ParseOptions, ParserContext};
@@ -1519,6 +1521,29 @@ mod test {
         assert!(NAME_PATTERN_REG.is_match("hello"));
     }
 
+    #[test]
+    fn test_merge_options() {
+        let schema_opts = SchemaNameValue {
+            ttl: Some(Duration::from_secs(3600)),
+        };
+
+        // Inherits the schema ttl if the table doesn't specify one.
+        let table_opts = merge_options(TableOptions::default(), schema_opts.clone());
+        assert_eq!(Some(Duration::from_secs(3600)), table_opts.ttl);
+
+        // The table ttl takes precedence over the schema ttl.
+        let table_opts = TableOptions {
+            ttl: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        let table_opts = merge_options(table_opts, schema_opts);
+        assert_eq!(Some(Duration::from_secs(60)), table_opts.ttl);
+
+        // Leaves the ttl unset if neither specifies one.
+        let table_opts = merge_options(TableOptions::default(), SchemaNameValue::default());
+        assert!(table_opts.ttl.is_none());
+    }
+
     #[test]
     fn test_validate_partition_columns() {
         let create_table = CreateTableExpr {
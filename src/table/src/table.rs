@@ -86,6 +86,11 @@ impl Table {
         self.data_source.clone()
     }
 
+    /// Get the filter pushdown type of this table.
+    pub fn filter_pushdown(&self) -> FilterPushDownType {
+        self.filter_pushdown
+    }
+
     /// Get a reference to the schema for this table.
     pub fn schema(&self) -> SchemaRef {
         self.table_info.meta.schema.clone()
@@ -19,11 +19,11 @@ use catalog::kvbackend::KvBackendCatalogManager;
 use cmd::error::StartFlownodeSnafu;
 use cmd::standalone::StandaloneOptions;
 use common_base::Plugins;
-use common_catalog::consts::{MIN_USER_FLOW_ID, MIN_USER_TABLE_ID};
+use common_catalog::consts::MIN_USER_FLOW_ID;
 use common_config::KvBackendConfig;
 use common_meta::cache::LayeredCacheRegistryBuilder;
 use common_meta::ddl::flow_meta::FlowMetadataAllocator;
-use common_meta::ddl::table_meta::TableMetadataAllocator;
+use common_meta::ddl::table_meta::{table_id_sequence_builder, TableMetadataAllocator};
 use common_meta::ddl::{DdlContext, NoopRegionFailureDetectorControl};
 use common_meta::ddl_manager::DdlManager;
 use common_meta::key::flow::FlowMetadataManager;
@@ -165,12 +165,8 @@ impl GreptimeDbStandaloneBuilder {
             flow_server: flownode.flow_worker_manager(),
         });
 
-        let table_id_sequence = Arc::new(
-            SequenceBuilder::new(TABLE_ID_SEQ, kv_backend.clone())
-                .initial(MIN_USER_TABLE_ID as u64)
-                .step(10)
-                .build(),
-        );
+        let table_id_sequence =
+            Arc::new(table_id_sequence_builder(TABLE_ID_SEQ, kv_backend.clone()).build());
         let flow_id_sequence = Arc::new(
             SequenceBuilder::new(FLOW_ID_SEQ, kv_backend.clone())
                 .initial(MIN_USER_FLOW_ID as u64)